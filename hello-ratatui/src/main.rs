@@ -1,27 +1,13 @@
-use crossterm::{
-    event::{self, KeyCode, KeyEventKind},
-    terminal::{
-	disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
-    },
-    ExecutableCommand,
-};
-use ratatui::{
-    prelude::{CrosstermBackend, Stylize, Terminal},
-    widgets::Paragraph,
-};
-use std::io::{stdout, Result};
+use crossterm::event::{self, KeyCode, KeyEventKind};
+use ratatui::prelude::Stylize;
+use std::io::Result;
+
+mod tui;
 
 // Main function for running application
 fn main() -> Result<()> {
-    // Allow app to render what it needs without disturbing shell output
-    stdout().execute(EnterAlternateScreen)?;
-
-    // Turn off input and output processing
-    enable_raw_mode()?;
-
-    // Create backend and clear screen
-    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
-    terminal.clear()?;
+    // Terminal setup, panic-safe via tui::init's installed panic hook
+    let mut terminal = tui::init();
 
     // Main loop to maintain app until quit
     loop {
@@ -32,7 +18,7 @@ fn main() -> Result<()> {
 
 	    // Render paragraph widget
 	    frame.render_widget(
-		Paragraph::new("Hello Ratatui!")
+		ratatui::widgets::Paragraph::new("Hello Ratatui!")
 		    // White foreground on blue background
 		    .white()
 		    .on_blue(),
@@ -56,10 +42,8 @@ fn main() -> Result<()> {
 	    }
 	}
     }
-    
+
     // Revert modifications to terminal
-    stdout().execute(LeaveAlternateScreen)?;
-    disable_raw_mode()?;
+    tui::restore()?;
     Ok(())
 }
-    
@@ -0,0 +1,40 @@
+// Vendored per app rather than shared - see the repo-root README
+use std::io::{self, stdout, Stdout};
+use std::panic;
+
+use crossterm::{execute, terminal::*};
+use ratatui::prelude::*;
+
+// Type alias for terminal type used in app
+pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+// Initialise terminal, panicking on failure. Installs a panic hook so a
+// panic mid-draw restores the terminal before printing its backtrace
+pub fn init() -> DefaultTerminal {
+    try_init().expect("failed to initialise terminal")
+}
+
+// Like `init`, but lets the caller handle a setup failure instead of panicking
+pub fn try_init() -> io::Result<DefaultTerminal> {
+    execute!(stdout(), EnterAlternateScreen)?;
+    enable_raw_mode()?;
+    set_panic_hook();
+    Terminal::new(CrosstermBackend::new(stdout()))
+}
+
+// Restore the terminal before letting the previous panic hook run, so a
+// panic's backtrace prints to a clean terminal instead of a corrupted one
+fn set_panic_hook() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+	let _ = restore();
+	hook(panic_info);
+    }));
+}
+
+// Restore terminal to original state
+pub fn restore() -> io::Result<()> {
+    execute!(stdout(), LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use color_eyre::Result;
+
+// Install color_eyre's panic/error report hooks, so a panic or an error
+// bubbling out of `main` prints a nicer, colourised report instead of the
+// default one-liner.
+//
+// This must run before `tui::init_with_options`: that function installs its
+// own panic hook on top of whatever is already set, so it restores the
+// terminal first and then defers to the hook installed here for the actual
+// report formatting.
+pub fn install_hooks() -> Result<()> {
+    color_eyre::install()
+}
@@ -1,8 +1,9 @@
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     prelude::*,
     symbols::border,
     widgets::{block::*, *},
+    Viewport,
 };
 
 use color_eyre::{
@@ -13,24 +14,73 @@ use color_eyre::{
 // Add errors module
 mod errors;
 
+// Define events module for the unified input/tick/resize event loop
+mod events;
+
 // Define tui module for terminal setup
 mod tui;
 
+use events::{AppEvent, EventHandler};
+
 // Contain app state within struct (enum instead if state is more complex)
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct App {
     counter: u8,
     exit: bool,
+    min: u8,                        // Lowest value the counter can reach
+    max: u8,                        // Highest value the counter can reach
+    step: u8,                       // Amount each increment/decrement changes the counter by
+    last_error: Option<String>,     // Most recent recoverable error, shown under the value until the next successful action
+}
+
+// Defaults for `min`/`max`/`step` when the corresponding `--min`/`--max`/`--step`
+// flag isn't passed on the command line
+const DEFAULT_MIN: u8 = 0;
+const DEFAULT_MAX: u8 = 2;
+const DEFAULT_STEP: u8 = 1;
+
+impl Default for App {
+    fn default() -> Self {
+	Self {
+	    counter: 0,
+	    exit: false,
+	    min: DEFAULT_MIN,
+	    max: DEFAULT_MAX,
+	    step: DEFAULT_STEP,
+	    last_error: None,
+	}
+    }
 }
 
 // App implementation
 impl App {
-    // Run app's main loop until user quits
-    pub fn run(&mut self, terminal: &mut tui::Tui) -> Result<()> {
+    // Construct app state with custom bounds/step instead of the defaults,
+    // starting the counter at `min`
+    pub fn new(min: u8, max: u8, step: u8) -> Self {
+	Self {
+	    counter: min,
+	    min,
+	    max,
+	    step,
+	    ..Self::default()
+	}
+    }
+
+    // Run app's main loop until user quits. `inline` should match whatever
+    // viewport the terminal was created with
+    pub fn run(&mut self, terminal: &mut tui::DefaultTerminal, inline: bool) -> Result<()> {
+	let mut events = EventHandler::default();
+
 	while !self.exit {
 	    // Draw terminal frames and handle events
 	    terminal.draw(|frame| self.render_frame(frame))?;
-	    self.handle_events().wrap_err("handle events failed")?;
+	    self.handle_event(events.next()?)?;
+	}
+	if inline {
+	    // Draw the final frame once more and move past it, so it scrolls
+	    // into the normal scrollback instead of being overwritten
+	    terminal.draw(|frame| self.render_frame(frame))?;
+	    println!();
 	}
 	Ok(())
     }
@@ -41,28 +91,45 @@ impl App {
 	frame.render_widget(self, frame.size());
     }
 
-    // Update app state based on user input
-    // DOES NOT HANDLE OTHER TASKS, INSTEAD USE "event::poll"
-    fn handle_events(&mut self) -> Result<()> {
-	match event::read()? {
-	    // Only listen to press
-	    Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-		self.handle_key_event(key_event).wrap_err_with(|| {
-		    format!("handling key event failed:\n{key_event:#?}")
-		})
+    // Dispatch a unified app event: keypresses, resizes, and idle ticks
+    fn handle_event(&mut self, event: AppEvent) -> Result<()> {
+	match event {
+	    AppEvent::Key(key_event) => self.handle_key_event(key_event).wrap_err_with(|| {
+		format!("handling key event failed:\n{key_event:#?}")
+	    }),
+	    // Nothing to do: `terminal.draw` already redraws to the new size
+	    // on the next pass through the loop
+	    AppEvent::Resize(_, _) => Ok(()),
+	    AppEvent::Mouse(_) => Ok(()),
+	    AppEvent::Tick => {
+		self.on_tick();
+		Ok(())
 	    }
-	    _ => Ok(())
 	}
     }
 
-    // Specific keypresses
+    // Hook for periodic work that doesn't depend on user input (animation,
+    // autosave, clock updates, ...). No-op for now
+    fn on_tick(&mut self) {}
+
+    // Specific keypresses. Recoverable errors (hitting a bound) are recorded
+    // on `last_error` to show under the value rather than aborting the app
     fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
-	match key_event.code {
-	    KeyCode::Char('q') => self.exit(),
-	    KeyCode::Left => self.decrement_counter()?,
-	    KeyCode::Right => self.increment_counter()?,
-	    _ => {}
+	let outcome = match key_event.code {
+	    KeyCode::Char('q') => {
+		self.exit();
+		Ok(())
+	    }
+	    KeyCode::Left => self.decrement_counter(),
+	    KeyCode::Right => self.increment_counter(),
+	    _ => Ok(()),
+	};
+
+	match outcome {
+	    Ok(()) => self.last_error = None,
+	    Err(err) => self.last_error = Some(err.to_string()),
 	}
+
 	Ok(())
     }
 
@@ -71,15 +138,22 @@ impl App {
 	self.exit = true;
     }
     fn decrement_counter(&mut self) -> Result<()> {
-	self.counter -= 1;
-	Ok(())
+	match self.counter.checked_sub(self.step) {
+	    Some(value) if value >= self.min => {
+		self.counter = value;
+		Ok(())
+	    }
+	    _ => bail!("counter underflow"),
+	}
     }
     fn increment_counter(&mut self) -> Result<()> {
-	self.counter += 1;
-	if self.counter > 2 {
-	    bail!("counter overflow");
+	match self.counter.checked_add(self.step) {
+	    Some(value) if value <= self.max => {
+		self.counter = value;
+		Ok(())
+	    }
+	    _ => bail!("counter overflow"),
 	}
-	Ok(())
     }
 }
 
@@ -106,10 +180,14 @@ impl Widget for &App {
 	    .borders(Borders::ALL)
 	    .border_set(border::THICK);
 
-	let counter_text = Text::from(vec![Line::from(vec![
+	let mut lines = vec![Line::from(vec![
 	    "Value: ".into(),
 	    self.counter.to_string().yellow(),
-	])]);
+	])];
+	if let Some(error) = &self.last_error {
+	    lines.push(Line::from(error.clone().red()));
+	}
+	let counter_text = Text::from(lines);
 
 	Paragraph::new(counter_text)
 	    .centered()
@@ -168,38 +246,70 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "attempt to subtract with overflow")]
-    fn handle_key_event_panic() {
+    fn handle_key_event_underflow_is_recoverable() {
         let mut app = App::default();
-        let _ = app.handle_key_event(KeyCode::Left.into());
+        assert!(app.handle_key_event(KeyCode::Left.into()).is_ok());
+        assert_eq!(app.counter, 0);
+        assert_eq!(app.last_error.as_deref(), Some("counter underflow"));
     }
 
     #[test]
-    fn handle_key_event_overflow() {
+    fn handle_key_event_overflow_is_recoverable() {
         let mut app = App::default();
         assert!(app.handle_key_event(KeyCode::Right.into()).is_ok());
         assert!(app.handle_key_event(KeyCode::Right.into()).is_ok());
-        assert_eq!(
-            app.handle_key_event(KeyCode::Right.into())
-                .unwrap_err()
-                .to_string(),
-            "counter overflow"
-        );
+        assert!(app.handle_key_event(KeyCode::Right.into()).is_ok());
+
+        assert_eq!(app.counter, 2);
+        assert_eq!(app.last_error.as_deref(), Some("counter overflow"));
     }
+
+    #[test]
+    fn successful_action_clears_last_error() {
+        let mut app = App::default();
+        app.handle_key_event(KeyCode::Left.into()).unwrap();
+        assert!(app.last_error.is_some());
+
+        app.handle_key_event(KeyCode::Right.into()).unwrap();
+        assert!(app.last_error.is_none());
+    }
+}
+
+// Parse an optional `--flag=value` argument into a u8, falling back to `default`
+fn parse_u8_flag(flag: &str, default: u8) -> u8 {
+    std::env::args()
+	.find_map(|arg| arg.strip_prefix(flag).map(str::to_string))
+	.and_then(|value| value.parse().ok())
+	.unwrap_or(default)
 }
 
 fn main() -> Result<()> {
     // Error handling
     errors::install_hooks()?;
-    
+
+    // Pass `--inline` to render beneath the shell prompt instead of
+    // taking over the whole screen
+    let inline = std::env::args().any(|arg| arg == "--inline");
+    let viewport = if inline {
+	Viewport::Inline(4)
+    } else {
+	Viewport::Fullscreen
+    };
+
+    // Pass `--min=`/`--max=`/`--step=` to change the counter's bounds and
+    // increment size instead of accepting the defaults
+    let min = parse_u8_flag("--min=", DEFAULT_MIN);
+    let max = parse_u8_flag("--max=", DEFAULT_MAX);
+    let step = parse_u8_flag("--step=", DEFAULT_STEP);
+
     // Terminal setup
-    let mut terminal = tui::init()?;
+    let mut terminal = tui::init_with_options(viewport.clone());
 
-    // Create and run app with default state (0 and false for App struct)
-    App::default().run(&mut terminal)?;
+    // Create and run app with the resolved bounds/step
+    App::new(min, max, step).run(&mut terminal, inline)?;
 
     // Restore terminal
-    tui::restore()?;
-    
+    tui::restore(viewport)?;
+
     Ok(())
 }
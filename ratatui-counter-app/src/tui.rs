@@ -1,21 +1,49 @@
+// Vendored per app rather than shared - see the repo-root README
 use std::io::{self, stdout, Stdout};
+use std::panic;
 
 use crossterm::{execute, terminal::*};
 use ratatui::prelude::*;
+use ratatui::{TerminalOptions, Viewport};
 
 // Type alias for terminal type used in app
-pub type Tui = Terminal<CrosstermBackend<Stdout>>;
+pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
 
-// Initialise terminal
-pub fn init() -> io::Result<Tui> {
-    execute!(stdout(), EnterAlternateScreen)?;
+// Initialise a terminal with the given viewport, panicking on failure.
+// Installs a panic hook so a panic mid-draw restores the terminal before
+// printing its backtrace
+pub fn init_with_options(viewport: Viewport) -> DefaultTerminal {
+    try_init_with_options(viewport).expect("failed to initialise terminal")
+}
+
+// Like `init_with_options`, but returns a setup failure instead of panicking
+pub fn try_init_with_options(viewport: Viewport) -> io::Result<DefaultTerminal> {
+    // Only a fullscreen viewport takes over the whole screen; inline/fixed
+    // viewports render in place among the existing shell scrollback
+    if viewport == Viewport::Fullscreen {
+	execute!(stdout(), EnterAlternateScreen)?;
+    }
     enable_raw_mode()?;
-    Terminal::new(CrosstermBackend::new(stdout()))
+    set_panic_hook(viewport.clone());
+    Terminal::with_options(CrosstermBackend::new(stdout()), TerminalOptions { viewport })
+}
+
+// Restore the terminal before letting the previous panic hook run, so a
+// panic's backtrace prints to a clean terminal instead of a corrupted one
+fn set_panic_hook(viewport: Viewport) {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+	let _ = restore(viewport.clone());
+	hook(panic_info);
+    }));
 }
 
-// Restore terminal to original state
-pub fn restore() -> io::Result<()> {
-    execute!(stdout(), LeaveAlternateScreen)?;
+// Restore terminal to original state. `viewport` must match whatever was
+// passed to `init_with_options`/`try_init_with_options`
+pub fn restore(viewport: Viewport) -> io::Result<()> {
+    if viewport == Viewport::Fullscreen {
+	execute!(stdout(), LeaveAlternateScreen)?;
+    }
     disable_raw_mode()?;
     Ok(())
 }
@@ -1,3 +1,13 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde_json::{Map, Value};
+
+use crate::input::TextInput;
+
 pub enum CurrentScreen {
     Main,
     Editing,
@@ -9,12 +19,29 @@ pub enum CurrentlyEditing {
     Value,
 }
 
+// Error returned by `App::from_file` when the file can't be read, isn't
+// valid JSON, or doesn't have an object at its top level
+#[derive(Debug)]
+pub struct LoadError(String);
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+	write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
 pub struct App {
-    pub key_input: String,                            // JSON key that is currently being edited
-    pub value_input: String,                          // JSON value that is currently being edited
-    pub pairs: HashMap<String, String>,               // Key-value pairs with serde Serialise support
+    pub key_input: TextInput,                         // JSON key that is currently being edited
+    pub value_input: TextInput,                       // JSON value that is currently being edited
+    pub pairs: HashMap<String, Value>,                // Key-value pairs with serde Serialise support
     pub current_screen: CurrentScreen,                // Current screen being rendered
     pub currently_editing: Option<CurrentlyEditing>,  // Which pair is being edited, if at all
+    pub force_raw_string: bool,                       // Ctrl-t: store value_input as a string even if it parses as something else
+    pub file_path: Option<PathBuf>,                   // Path the pairs were loaded from / are saved to, if any
+    pub dirty: bool,                                  // Whether pairs have unsaved changes
+    pub status_message: Option<String>,               // Most recent save/load status, shown in the footer
 }
 
 // App struct implementation
@@ -22,23 +49,104 @@ impl App {
     // Universal state defaults for creation of state
     pub fn new() -> App {
 	App {
-	    key_input: String::new(),
-	    value_input: String::new(),
+	    key_input: TextInput::new(),
+	    value_input: TextInput::new(),
 	    pairs: HashMap::new(),
 	    current_screen: CurrentScreen::Main,
 	    currently_editing: None,
+	    force_raw_string: false,
+	    file_path: None,
+	    dirty: false,
+	    status_message: None,
+	}
+    }
+
+    // Load an existing JSON object from `path` into a fresh App
+    pub fn from_file(path: PathBuf) -> Result<App, LoadError> {
+	let contents = fs::read_to_string(&path)
+	    .map_err(|err| LoadError(format!("failed to read {}: {err}", path.display())))?;
+
+	let value: Value = serde_json::from_str(&contents)
+	    .map_err(|err| LoadError(format!("invalid JSON in {}: {err}", path.display())))?;
+
+	let Value::Object(map) = value else {
+	    return Err(LoadError(format!(
+		"{} does not contain a JSON object at the top level",
+		path.display()
+	    )));
+	};
+
+	let mut app = App::new();
+	app.pairs = map.into_iter().collect();
+	app.file_path = Some(path);
+	Ok(app)
+    }
+
+    // Write the current pairs back to `file_path`, if one was set
+    pub fn save_to_file(&mut self) -> io::Result<()> {
+	let Some(path) = self.file_path.clone() else {
+	    return Err(io::Error::new(
+		io::ErrorKind::NotFound,
+		"no file path to save to; start the app with a file argument first",
+	    ));
+	};
+
+	let mut map = Map::new();
+	for (key, value) in &self.pairs {
+	    map.insert(key.clone(), value.clone());
+	}
+
+	let file = fs::File::create(&path)?;
+	serde_json::to_writer_pretty(file, &Value::Object(map))
+	    .map_err(io::Error::other)?;
+
+	self.dirty = false;
+	Ok(())
+    }
+
+    // Infer the JSON type the current value_input would be saved as, for display in the editing UI
+    pub fn value_input_type(&self) -> &'static str {
+	if self.force_raw_string {
+	    return "string (forced)";
+	}
+
+	match serde_json::from_str::<Value>(self.value_input.value()) {
+	    Ok(Value::Null) => "null",
+	    Ok(Value::Bool(_)) => "bool",
+	    Ok(Value::Number(_)) => "number",
+	    Ok(Value::Array(_)) => "array",
+	    Ok(Value::Object(_)) => "object",
+	    Ok(Value::String(_)) | Err(_) => "string",
 	}
     }
 
+    // Toggle whether the value being edited is forced to be stored as a raw string
+    pub fn toggle_force_raw_string(&mut self) {
+	self.force_raw_string = !self.force_raw_string;
+    }
+
     // Save key-value pair that is currently being edited
     pub fn save_key_value(&mut self) {
+	// Parse the typed value as JSON, falling back to a plain string when it
+	// doesn't parse or when the user forced raw-string mode with Ctrl-t
+	let value = if self.force_raw_string {
+	    Value::String(self.value_input.value().to_string())
+	} else {
+	    serde_json::from_str(self.value_input.value())
+		.unwrap_or_else(|_| Value::String(self.value_input.value().to_string()))
+	};
+
 	// Add stored inputs in HashMap
-	self.pairs
-	    .insert(self.key_input.clone(), self.value_input.clone());
+	self.pairs.insert(self.key_input.value().to_string(), value);
+	self.dirty = true;
+
+	// A fresh edit makes any previous save/load status stale
+	self.status_message = None;
 
 	// Reset editing state
-	self.key_input = String::new();
-	self.value_input = String::new();
+	self.key_input.clear();
+	self.value_input.clear();
+	self.force_raw_string = false;
 	self.currently_editing = None;
     }
 
@@ -61,10 +169,95 @@ impl App {
 	}
     }
 
-    // Print serialised JSON from all key-value pairs
-    pub fn print_json(&self) -> Result<()> {
-	// Serialise pairs to string
-	let output = serde_json::to_string(&self.pairs)?;
+    // Print serialised JSON from all key-value pairs as a proper typed JSON object
+    pub fn print_json(&self) -> serde_json::Result<()> {
+	let mut map = Map::new();
+	for (key, value) in &self.pairs {
+	    map.insert(key.clone(), value.clone());
+	}
+
+	let output = serde_json::to_string_pretty(&Value::Object(map))?;
 	println!("{}", output);
 	Ok(())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fill in the key/value inputs as if the user had typed them, then save
+    fn type_and_save(app: &mut App, key: &str, value: &str) {
+	for ch in key.chars() {
+	    app.key_input.insert(ch);
+	}
+	for ch in value.chars() {
+	    app.value_input.insert(ch);
+	}
+	app.save_key_value();
+    }
+
+    #[test]
+    fn save_key_value_infers_the_json_type_from_syntax() {
+	let mut app = App::new();
+	type_and_save(&mut app, "count", "42");
+	assert_eq!(app.pairs["count"], Value::Number(42.into()));
+
+	let mut app = App::new();
+	type_and_save(&mut app, "flag", "true");
+	assert_eq!(app.pairs["flag"], Value::Bool(true));
+
+	let mut app = App::new();
+	type_and_save(&mut app, "obj", r#"{"a":1}"#);
+	assert_eq!(app.pairs["obj"], serde_json::json!({"a": 1}));
+    }
+
+    #[test]
+    fn save_key_value_falls_back_to_string_when_not_valid_json() {
+	let mut app = App::new();
+	type_and_save(&mut app, "name", "not json");
+	assert_eq!(app.pairs["name"], Value::String("not json".to_string()));
+    }
+
+    #[test]
+    fn force_raw_string_overrides_type_inference() {
+	let mut app = App::new();
+	app.toggle_force_raw_string();
+	type_and_save(&mut app, "count", "42");
+	assert_eq!(app.pairs["count"], Value::String("42".to_string()));
+    }
+
+    // Write `contents` to a fresh temp file and return its path
+    fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+	let path = std::env::temp_dir().join(name);
+	fs::write(&path, contents).unwrap();
+	path
+    }
+
+    #[test]
+    fn from_file_loads_a_top_level_object() {
+	let path = write_temp_file(
+	    "ratatui-json-editor-test-object.json",
+	    r#"{"a": 1, "b": "two"}"#,
+	);
+	let app = App::from_file(path.clone()).unwrap();
+	assert_eq!(app.pairs["a"], Value::Number(1.into()));
+	assert_eq!(app.pairs["b"], Value::String("two".to_string()));
+	assert_eq!(app.file_path, Some(path.clone()));
+	fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn from_file_rejects_a_top_level_array() {
+	let path = write_temp_file("ratatui-json-editor-test-array.json", "[1, 2, 3]");
+	assert!(App::from_file(path.clone()).is_err());
+	fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn from_file_rejects_a_top_level_string() {
+	let path = write_temp_file("ratatui-json-editor-test-string.json", r#""hello""#);
+	assert!(App::from_file(path.clone()).is_err());
+	fs::remove_file(path).unwrap();
+    }
+}
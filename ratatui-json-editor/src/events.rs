@@ -0,0 +1,71 @@
+// Vendored per app rather than shared - see the repo-root README
+use std::io;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, KeyEventKind, MouseEvent};
+
+// Unified terminal event, produced by `EventHandler::next` in place of raw
+// crossterm events, so the main loop can also react to ticks
+#[derive(Clone, Copy, Debug)]
+pub enum AppEvent {
+    Key(KeyEvent),
+    // Neither app reads the payload yet; kept so callers can match on them
+    // explicitly instead of lumping them in with `_`
+    #[allow(dead_code)]
+    Mouse(MouseEvent),
+    #[allow(dead_code)]
+    Resize(u16, u16),
+    Tick,
+}
+
+// Polls crossterm for input, emitting `AppEvent::Tick` at `tick_rate` when
+// nothing else arrives in time, so the app can do periodic work even while
+// idle (autosave, clock updates, ...)
+pub struct EventHandler {
+    tick_rate: Duration,
+    last_tick: Instant,
+}
+
+impl EventHandler {
+    pub fn new(tick_rate: Duration) -> Self {
+	Self {
+	    tick_rate,
+	    last_tick: Instant::now(),
+	}
+    }
+
+    // Block until the next input event or the next tick. The poll timeout is
+    // recomputed on every pass so ticks stay evenly spaced even when handling
+    // an event takes a while
+    pub fn next(&mut self) -> io::Result<AppEvent> {
+	loop {
+	    let timeout = self.tick_rate.saturating_sub(self.last_tick.elapsed());
+
+	    if event::poll(timeout)? {
+		match event::read()? {
+		    CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+			return Ok(AppEvent::Key(key));
+		    }
+		    CrosstermEvent::Mouse(mouse) => return Ok(AppEvent::Mouse(mouse)),
+		    CrosstermEvent::Resize(width, height) => {
+			return Ok(AppEvent::Resize(width, height))
+		    }
+		    // Ignore key releases/repeats and anything else
+		    _ => continue,
+		}
+	    }
+
+	    if self.last_tick.elapsed() >= self.tick_rate {
+		self.last_tick = Instant::now();
+		return Ok(AppEvent::Tick);
+	    }
+	}
+    }
+}
+
+impl Default for EventHandler {
+    // 4 ticks per second
+    fn default() -> Self {
+	Self::new(Duration::from_millis(250))
+    }
+}
@@ -0,0 +1,140 @@
+// A small reusable cursor-aware text input, used for both the key and value
+// fields in the editing screen. The cursor is tracked as a character index
+// (not a byte index) so it can never land inside a multi-byte UTF-8 character.
+// It moves one `char` at a time, so it can stop between the chars of a
+// multi-char grapheme cluster (e.g. a letter followed by a combining accent).
+#[derive(Debug, Default, Clone)]
+pub struct TextInput {
+    value: String,
+    cursor: usize,
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+	Self::default()
+    }
+
+    pub fn value(&self) -> &str {
+	&self.value
+    }
+
+    // Cursor position in characters, for use with Frame::set_cursor
+    pub fn cursor(&self) -> usize {
+	self.cursor
+    }
+
+    fn char_count(&self) -> usize {
+	self.value.chars().count()
+    }
+
+    // Byte offset into `value` that corresponds to `char_index` characters in
+    fn byte_index(&self, char_index: usize) -> usize {
+	self.value
+	    .char_indices()
+	    .nth(char_index)
+	    .map(|(i, _)| i)
+	    .unwrap_or(self.value.len())
+    }
+
+    // Insert a character at the cursor and advance the cursor past it
+    pub fn insert(&mut self, c: char) {
+	let idx = self.byte_index(self.cursor);
+	self.value.insert(idx, c);
+	self.cursor += 1;
+    }
+
+    // Delete the character immediately before the cursor
+    pub fn backspace(&mut self) {
+	if self.cursor == 0 {
+	    return;
+	}
+	let start = self.byte_index(self.cursor - 1);
+	let end = self.byte_index(self.cursor);
+	self.value.replace_range(start..end, "");
+	self.cursor -= 1;
+    }
+
+    // Delete the character immediately after the cursor
+    pub fn delete(&mut self) {
+	if self.cursor >= self.char_count() {
+	    return;
+	}
+	let start = self.byte_index(self.cursor);
+	let end = self.byte_index(self.cursor + 1);
+	self.value.replace_range(start..end, "");
+    }
+
+    pub fn move_left(&mut self) {
+	self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+	self.cursor = (self.cursor + 1).min(self.char_count());
+    }
+
+    pub fn move_home(&mut self) {
+	self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+	self.cursor = self.char_count();
+    }
+
+    pub fn clear(&mut self) {
+	self.value.clear();
+	self.cursor = 0;
+    }
+}
+
+impl From<String> for TextInput {
+    fn from(value: String) -> Self {
+	let cursor = value.chars().count();
+	TextInput { value, cursor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_backspace_move_cursor() {
+	let mut input = TextInput::new();
+	input.insert('a');
+	input.insert('b');
+	input.insert('c');
+	assert_eq!(input.value(), "abc");
+	assert_eq!(input.cursor(), 3);
+
+	input.move_left();
+	input.backspace();
+	assert_eq!(input.value(), "ac");
+	assert_eq!(input.cursor(), 1);
+    }
+
+    #[test]
+    fn backspace_and_delete_respect_multi_byte_characters() {
+	let mut input = TextInput::from("héllo".to_string());
+	input.move_home();
+	input.move_right();
+	input.move_right();
+	// Cursor now sits right after the 'é'
+	input.backspace();
+	assert_eq!(input.value(), "hllo");
+
+	input.delete();
+	assert_eq!(input.value(), "hlo");
+    }
+
+    #[test]
+    fn cursor_cannot_move_past_either_end() {
+	let mut input = TextInput::from("hi".to_string());
+	input.move_home();
+	input.move_left();
+	assert_eq!(input.cursor(), 0);
+
+	input.move_end();
+	input.move_right();
+	assert_eq!(input.cursor(), 2);
+    }
+}
@@ -1,38 +1,37 @@
-// Imports for terminal setup
-use crossterm::event::EnableMouseCapture;
-use crossterm::execute;
-use crossterm::terminal::{enable_raw_mode, EnterAlternateScreen};
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::backend::Backend;
+use ratatui::Terminal;
 use std::io;
+use std::path::PathBuf;
 
-// Imports for restoring terminal
-use crossterm::event::DisableMouseCapture;
-use crossterm::terminal::{disable_raw_mode, LeaveAlternateScreen};
+mod app;
+mod events;
+mod input;
+mod tui;
+mod ui;
 
-// Main function for startup, main loop, and cleanup
-fn main() -> Result<(), Box<dyn Error>> {
-    // Setup terminal
-    enable_raw_mode()?;
-
-    // Allow user to pipe output into external programs like ratatui > output.json
-    // Otherwide using stdout would be fine
-    let mut stderr = io::stderr();
-    execute!(stderr, EnterAlternateScreen, EnableMouseCapture)?;
-
-    // Create backend
-    let backend = CrosstermBackend::new(stderr);
-    let mut terminal = Terminal::new(backend)?;
+use app::{App, CurrentScreen, CurrentlyEditing};
+use events::{AppEvent, EventHandler};
+use ui::ui;
 
-    // Create application instance and run it
-    let mut app = App::new();
+// Main function for startup, main loop, and cleanup
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Terminal setup, panic-safe via tui::init's installed panic hook
+    let mut terminal = tui::init();
+
+    // Create application instance, loading an existing file if one was given
+    let mut app = match std::env::args().nth(1) {
+	Some(path) => App::from_file(PathBuf::from(path)).unwrap_or_else(|err| {
+	    let mut app = App::new();
+	    app.status_message = Some(format!("{err}"));
+	    app
+	}),
+	None => App::new(),
+    };
     let res = run_app(&mut terminal, &mut app);
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-	terminal.backend_mut(),
-	LeaveAlternateScreen,
-	DisableMouseCapture
-    )?;
+    tui::restore()?;
     terminal.show_cursor()?;
 
     // If run_app returned Ok state, check if JSON should be printed
@@ -41,7 +40,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 	if do_print {
 	    app.print_json()?;
 	}
-    } else if let Err(err) = err {
+    } else if let Err(err) = res {
 	println!("{err:?}");
     }
 
@@ -54,23 +53,36 @@ fn run_app<B: Backend>(          // Start method signature across ratatui::backe
     app: &mut App,               // Mutable borrow to application state object
 ) -> io::Result<bool> {          // Return whether was io error with Err state and an Ok(bool) to know if printing JSON
 
+    // Emits Tick events when idle and Resize when the terminal is resized,
+    // on top of the keyboard events the loop used to poll directly
+    let mut events = EventHandler::default();
+
     // Event/UI loop update
     loop {
 	// Pass f: <Frame> into ui function to be drawn
 	// Also pass immutable borrow of app state
 	terminal.draw(|f| ui(f, app))?;
 
-	// Polling for keyboard events
-	// Alternatively could use more complex "counter" tutorial method
-	if let Event::Key(key) = event::read()? {
-	    if key.kind == event::KeyEventKind:Release {
-		// Skip events that are not press
-		continue;
-	    }
-
-	    // Test for main screen
-	    match app.current_screen {
-		// Main screen only has two keys to match 
+	// Resize and tick events don't need handling here: a resize is
+	// picked up by `terminal.draw` on the next pass, and there's no
+	// periodic work (yet) to do on a tick
+	let key = match events.next()? {
+	    AppEvent::Key(key) => key,
+	    AppEvent::Resize(_, _) | AppEvent::Mouse(_) | AppEvent::Tick => continue,
+	};
+
+	// Test for main screen
+	match app.current_screen {
+		// Main screen: edit, quit, and Ctrl-s to save to the loaded file
+		CurrentScreen::Main
+		    if key.code == KeyCode::Char('s')
+			&& key.modifiers.contains(KeyModifiers::CONTROL) =>
+		{
+		    app.status_message = Some(match app.save_to_file() {
+			Ok(()) => "Saved.".to_string(),
+			Err(err) => format!("Save failed: {err}"),
+		    });
+		}
 		CurrentScreen::Main => match key.code {
 		    // Edit action
 		    KeyCode::Char('e') => {
@@ -78,6 +90,8 @@ fn run_app<B: Backend>(          // Start method signature across ratatui::backe
 			app.current_screen = CurrentScreen::Editing;
 			// Update editing state, starting user on key side
 			app.currently_editing = Some(CurrentlyEditing::Key);
+			// Dismiss any stale save/load status now that it's being acted on
+			app.status_message = None;
 		    }
 		    // Quit action
 		    KeyCode::Char('q') => {
@@ -100,7 +114,14 @@ fn run_app<B: Backend>(          // Start method signature across ratatui::backe
 		},
 
 		// Handle enter key for moving through edit mode and returning to main
-		CurrentScreen::Editing if key.kind == KeyEventKind::Press => {
+		CurrentScreen::Editing => {
+		    // Ctrl-t forces the value to be saved as a raw string, regardless
+		    // of whether it parses as a number/bool/object
+		    if key.code == KeyCode::Char('t') && key.modifiers.contains(KeyModifiers::CONTROL) {
+			app.toggle_force_raw_string();
+			continue;
+		    }
+
 		    match key.code {
 			// Check for Enter key
 			KeyCode::Enter => {
@@ -120,18 +141,56 @@ fn run_app<B: Backend>(          // Start method signature across ratatui::backe
 			    }
 			}
 
-			// Handle Backspace key for deleting characters
+			// Handle Backspace key, deleting the character before the cursor
 			KeyCode::Backspace => {
-			    // Check for editing
 			    if let Some(editing) = &app.currently_editing {
 				match editing {
-				    // Delete end characters off either key or value strings
-				    CurrentlyEditing::Key => {
-					app.key_input.pop();
-				    }
-				    CurrentlyEditing::Key => {
-					app.value_input.pop();
-				    }
+				    CurrentlyEditing::Key => app.key_input.backspace(),
+				    CurrentlyEditing::Value => app.value_input.backspace(),
+				}
+			    }
+			}
+
+			// Handle Delete key, deleting the character after the cursor
+			KeyCode::Delete => {
+			    if let Some(editing) = &app.currently_editing {
+				match editing {
+				    CurrentlyEditing::Key => app.key_input.delete(),
+				    CurrentlyEditing::Value => app.value_input.delete(),
+				}
+			    }
+			}
+
+			// Move the cursor within the field currently being edited
+			KeyCode::Left => {
+			    if let Some(editing) = &app.currently_editing {
+				match editing {
+				    CurrentlyEditing::Key => app.key_input.move_left(),
+				    CurrentlyEditing::Value => app.value_input.move_left(),
+				}
+			    }
+			}
+			KeyCode::Right => {
+			    if let Some(editing) = &app.currently_editing {
+				match editing {
+				    CurrentlyEditing::Key => app.key_input.move_right(),
+				    CurrentlyEditing::Value => app.value_input.move_right(),
+				}
+			    }
+			}
+			KeyCode::Home => {
+			    if let Some(editing) = &app.currently_editing {
+				match editing {
+				    CurrentlyEditing::Key => app.key_input.move_home(),
+				    CurrentlyEditing::Value => app.value_input.move_home(),
+				}
+			    }
+			}
+			KeyCode::End => {
+			    if let Some(editing) = &app.currently_editing {
+				match editing {
+				    CurrentlyEditing::Key => app.key_input.move_end(),
+				    CurrentlyEditing::Value => app.value_input.move_end(),
 				}
 			    }
 			}
@@ -141,30 +200,24 @@ fn run_app<B: Backend>(          // Start method signature across ratatui::backe
 			    app.current_screen = CurrentScreen::Main;
 			    app.currently_editing = None;
 			}
-			
+
 			// Swap between key and value
 			KeyCode::Tab => {
 			    app.toggle_editing();
 			}
 
-			// Handle typing valid characters by capturing value of char
+			// Handle typing valid characters by inserting at the cursor
 			KeyCode::Char(value) => {
 			    if let Some(editing) = &app.currently_editing {
 				match editing {
-				    CurrentlyEditing::Key => {
-					app.key_input.push(value);
-				    }
-				    CurrentlyEditing::Value => {
-					app.value_input.push(value);
-				    }
+				    CurrentlyEditing::Key => app.key_input.insert(value),
+				    CurrentlyEditing::Value => app.value_input.insert(value),
 				}
 			    }
 			}
 			_ => {}
 		    }
 		}
-		_ => {}
 	    }
-	}
     }
 }
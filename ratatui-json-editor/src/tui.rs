@@ -0,0 +1,42 @@
+// Vendored per app rather than shared - see the repo-root README
+use std::io::{self, stderr, Stderr};
+use std::panic;
+
+use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::{execute, terminal::*};
+use ratatui::prelude::*;
+
+// Output goes to stderr, not stdout, so the TUI frames never end up in a
+// `cargo run > out.json` redirect alongside the printed JSON
+pub type DefaultTerminal = Terminal<CrosstermBackend<Stderr>>;
+
+// Initialise terminal, panicking on failure. Installs a panic hook so a
+// panic mid-draw restores the terminal before printing its backtrace
+pub fn init() -> DefaultTerminal {
+    try_init().expect("failed to initialise terminal")
+}
+
+// Like `init`, but lets the caller handle a setup failure instead of panicking
+pub fn try_init() -> io::Result<DefaultTerminal> {
+    execute!(stderr(), EnterAlternateScreen, EnableMouseCapture)?;
+    enable_raw_mode()?;
+    set_panic_hook();
+    Terminal::new(CrosstermBackend::new(stderr()))
+}
+
+// Restore the terminal before letting the previous panic hook run, so a
+// panic's backtrace prints to a clean terminal instead of a corrupted one
+fn set_panic_hook() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+	let _ = restore();
+	hook(panic_info);
+    }));
+}
+
+// Restore terminal to original state
+pub fn restore() -> io::Result<()> {
+    execute!(stderr(), LeaveAlternateScreen, DisableMouseCapture)?;
+    disable_raw_mode()?;
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, CurrentScreen, CurrentlyEditing};
+
+// Draw the whole UI for the current app state
+pub fn ui(frame: &mut Frame, app: &App) {
+    // Overall vertical layout: title, body, footer key hints
+    let chunks = Layout::default()
+	.direction(Direction::Vertical)
+	.constraints([
+	    Constraint::Length(3),
+	    Constraint::Min(1),
+	    Constraint::Length(3),
+	])
+	.split(frame.size());
+
+    let title_block = Block::default()
+	.borders(Borders::ALL)
+	.style(Style::default());
+    let title = Paragraph::new(Text::styled(
+	"Create New JSON",
+	Style::default().add_modifier(Modifier::BOLD),
+    ))
+    .block(title_block);
+    frame.render_widget(title, chunks[0]);
+
+    // Render every stored pair as "key: value"
+    let mut list_items = Vec::<ListItem>::new();
+    for (key, value) in &app.pairs {
+	list_items.push(ListItem::new(Line::from(Span::styled(
+	    format!("{key}: {value}"),
+	    Style::default().fg(Color::Yellow),
+	))));
+    }
+    let list = List::new(list_items);
+    frame.render_widget(list, chunks[1]);
+
+    let current_navigation_text = vec![
+	match app.current_screen {
+	    CurrentScreen::Main => Span::styled("Normal Mode", Style::default().fg(Color::Green)),
+	    CurrentScreen::Editing => {
+		Span::styled("Editing Mode", Style::default().fg(Color::Yellow))
+	    }
+	    CurrentScreen::Exiting => Span::styled("Exiting", Style::default().fg(Color::LightRed)),
+	},
+	Span::styled(" | ", Style::default().fg(Color::White)),
+	match &app.currently_editing {
+	    Some(CurrentlyEditing::Key) => Span::styled("Editing Json Key", Style::default().fg(Color::Green)),
+	    Some(CurrentlyEditing::Value) => {
+		Span::styled(format!("Editing Json Value ({})", app.value_input_type()), Style::default().fg(Color::LightGreen))
+	    }
+	    None => Span::styled("Not Editing Anything", Style::default().fg(Color::DarkGray)),
+	},
+    ];
+    let mode_footer = Paragraph::new(Line::from(current_navigation_text))
+	.block(Block::default().borders(Borders::ALL));
+
+    let current_keys_hint = match app.current_screen {
+	CurrentScreen::Main => Span::styled(
+	    match &app.status_message {
+		Some(message) => message.clone(),
+		None => "(q) to quit / (e) to make new pair / (Ctrl-s) to save".to_string(),
+	    },
+	    Style::default().fg(Color::Red),
+	),
+	CurrentScreen::Editing => Span::styled(
+	    "(ESC) to cancel / (Tab) to switch / (Ctrl-t) force string / enter to complete",
+	    Style::default().fg(Color::Red),
+	),
+	CurrentScreen::Exiting => Span::styled(
+	    "(q) to quit / (y) to output and quit / (n) to quit without output",
+	    Style::default().fg(Color::Red),
+	),
+    };
+    let key_notes_footer = Paragraph::new(Line::from(current_keys_hint))
+	.block(Block::default().borders(Borders::ALL));
+
+    let footer_chunks = Layout::default()
+	.direction(Direction::Horizontal)
+	.constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+	.split(chunks[2]);
+    frame.render_widget(mode_footer, footer_chunks[0]);
+    frame.render_widget(key_notes_footer, footer_chunks[1]);
+
+    // Popup for editing a key-value pair
+    if let Some(editing) = &app.currently_editing {
+	let popup_block = Block::default()
+	    .title("Enter a new key-value pair")
+	    .borders(Borders::ALL)
+	    .style(Style::default());
+
+	let area = centered_rect(60, 25, frame.size());
+	frame.render_widget(Clear, area);
+	frame.render_widget(popup_block, area);
+
+	let popup_chunks = Layout::default()
+	    .direction(Direction::Horizontal)
+	    .margin(1)
+	    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+	    .split(area);
+
+	let mut key_block = Block::default().title("Key").borders(Borders::ALL);
+	let mut value_block = Block::default()
+	    .title(format!("Value ({})", app.value_input_type()))
+	    .borders(Borders::ALL);
+
+	let active_style = Style::default().fg(Color::LightGreen);
+	match editing {
+	    CurrentlyEditing::Key => key_block = key_block.style(active_style),
+	    CurrentlyEditing::Value => value_block = value_block.style(active_style),
+	};
+
+	let key_text = Paragraph::new(app.key_input.value()).block(key_block);
+	frame.render_widget(key_text, popup_chunks[0]);
+
+	let value_text = Paragraph::new(app.value_input.value()).block(value_block);
+	frame.render_widget(value_text, popup_chunks[1]);
+
+	// Place the real terminal cursor over whichever field is active, offset
+	// by 1 for the block's border and by the field's own character cursor
+	match editing {
+	    CurrentlyEditing::Key => frame.set_cursor(
+		popup_chunks[0].x + 1 + app.key_input.cursor() as u16,
+		popup_chunks[0].y + 1,
+	    ),
+	    CurrentlyEditing::Value => frame.set_cursor(
+		popup_chunks[1].x + 1 + app.value_input.cursor() as u16,
+		popup_chunks[1].y + 1,
+	    ),
+	}
+    }
+
+    // Exit confirmation popup
+    if let CurrentScreen::Exiting = app.current_screen {
+	frame.render_widget(Clear, frame.size());
+	let popup_block = Block::default()
+	    .title("Y/N")
+	    .borders(Borders::ALL)
+	    .style(Style::default());
+
+	let mut exit_message = String::from("Would you like to output the buffer as json? (y/n)");
+	if app.dirty {
+	    exit_message.push_str("\n(You have unsaved changes not written to the file.)");
+	}
+	let exit_text = Text::styled(exit_message, Style::default().fg(Color::Red));
+	let exit_paragraph = Paragraph::new(exit_text)
+	    .block(popup_block)
+	    .alignment(Alignment::Center);
+
+	let area = centered_rect(60, 25, frame.size());
+	frame.render_widget(exit_paragraph, area);
+    }
+}
+
+// Helper to carve a centered rectangle out of `r`, `percent_x` and `percent_y` wide/tall
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+	.direction(Direction::Vertical)
+	.constraints([
+	    Constraint::Percentage((100 - percent_y) / 2),
+	    Constraint::Percentage(percent_y),
+	    Constraint::Percentage((100 - percent_y) / 2),
+	])
+	.split(r);
+
+    Layout::default()
+	.direction(Direction::Horizontal)
+	.constraints([
+	    Constraint::Percentage((100 - percent_x) / 2),
+	    Constraint::Percentage(percent_x),
+	    Constraint::Percentage((100 - percent_x) / 2),
+	])
+	.split(popup_layout[1])[1]
+}